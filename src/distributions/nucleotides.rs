@@ -0,0 +1,130 @@
+use std::{error, fmt};
+
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+const ALPHABET: [u8; 5] = [b'A', b'C', b'G', b'T', b'N'];
+
+/// An error creating a [`Nucleotides`] distribution from an invalid base composition.
+///
+/// [`Nucleotides`]: struct.Nucleotides.html
+#[derive(Debug)]
+pub struct InvalidCompositionError {
+    gc_content: f64,
+    n_rate: f64,
+}
+
+impl fmt::Display for InvalidCompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "gc_content ({}) and n_rate ({}) must each be in the range [0.0, 1.0]",
+            self.gc_content, self.n_rate
+        )
+    }
+}
+
+impl error::Error for InvalidCompositionError {}
+
+/// A base composition generator.
+///
+/// Bases are drawn independently, with replacement, from a weighted alphabet derived from a
+/// target GC fraction and N-rate: `P(G) = P(C) = (1 - n) * g / 2`, `P(A) = P(T) = (1 - n) * (1 -
+/// g) / 2`, and `P(N) = n`. This lets generated reads mimic the base composition of a particular
+/// organism rather than assuming a uniform alphabet.
+pub struct Nucleotides {
+    distribution: WeightedIndex<f64>,
+}
+
+impl Nucleotides {
+    /// Creates a generator from a GC fraction and an N-rate.
+    ///
+    /// `gc_content` and `n_rate` are both expected to be in the range `[0.0, 1.0]`. An error is
+    /// returned otherwise, since out-of-range values would produce a negative sampling weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fqlib::distributions::Nucleotides;
+    ///
+    /// assert!(Nucleotides::new(0.6, 0.0).is_ok());
+    /// assert!(Nucleotides::new(1.5, 0.0).is_err());
+    /// ```
+    pub fn new(gc_content: f64, n_rate: f64) -> Result<Nucleotides, InvalidCompositionError> {
+        let in_range = |x: f64| (0.0..=1.0).contains(&x);
+
+        if !in_range(gc_content) || !in_range(n_rate) {
+            return Err(InvalidCompositionError { gc_content, n_rate });
+        }
+
+        let gc = (1.0 - n_rate) * gc_content / 2.0;
+        let at = (1.0 - n_rate) * (1.0 - gc_content) / 2.0;
+
+        let weights = [at, gc, gc, at, n_rate];
+
+        Ok(Nucleotides {
+            distribution: WeightedIndex::new(&weights).unwrap(),
+        })
+    }
+}
+
+impl Default for Nucleotides {
+    /// Creates a generator with a balanced (50%) GC content and no Ns.
+    fn default() -> Nucleotides {
+        Nucleotides::new(0.5, 0.0).unwrap()
+    }
+}
+
+impl Distribution<u8> for Nucleotides {
+    fn sample<R>(&self, rng: &mut R) -> u8
+    where
+        R: Rng + ?Sized,
+    {
+        ALPHABET[self.distribution.sample(rng)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_invalid_composition() {
+        assert!(Nucleotides::new(-0.1, 0.0).is_err());
+        assert!(Nucleotides::new(0.0, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_sample_is_from_alphabet() {
+        let nucleotides = Nucleotides::default();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let base = nucleotides.sample(&mut rng);
+            assert!(ALPHABET.contains(&base));
+        }
+    }
+
+    #[test]
+    fn test_sample_with_all_gc() {
+        let nucleotides = Nucleotides::new(1.0, 0.0).unwrap();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let base = nucleotides.sample(&mut rng);
+            assert!(base == b'C' || base == b'G');
+        }
+    }
+
+    #[test]
+    fn test_sample_with_all_n() {
+        let nucleotides = Nucleotides::new(0.5, 1.0).unwrap();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            assert_eq!(nucleotides.sample(&mut rng), b'N');
+        }
+    }
+}
@@ -1,34 +1,148 @@
+use std::{error, fmt};
+
 use rand::{distributions::Distribution, Rng};
 use rand_distr::Normal;
 
 const MIN: f64 = 0.0;
 const MAX: f64 = 41.0;
 
-const MEAN: f64 = (MIN + MAX) as f64 / 2.0;
-// std_dev = sqrt(MEAN / 3.0)
-const STD_DEV: f64 = 2.61;
+// A typical Illumina run plateaus at a high quality for most of the read, then degrades sharply
+// towards the 3' end.
+const Q_START: f64 = 38.0;
+const Q_END: f64 = 10.0;
+const SHAPE: f64 = 3.0;
+
+const STD_DEV_START: f64 = 2.0;
+const STD_DEV_END: f64 = 4.61;
+
+/// An error creating a [`QualityScores`] distribution from invalid parameters.
+///
+/// [`QualityScores`]: struct.QualityScores.html
+#[derive(Debug)]
+pub struct InvalidParametersError {
+    q_start: f64,
+    q_end: f64,
+    shape: f64,
+    std_dev_start: f64,
+    std_dev_end: f64,
+}
+
+impl fmt::Display for InvalidParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "q_start ({}) and q_end ({}) must each be in the range [{}, {}], shape ({}) must be \
+             positive, and std_dev_start ({}) and std_dev_end ({}) must each be non-negative",
+            self.q_start, self.q_end, MIN, MAX, self.shape, self.std_dev_start, self.std_dev_end
+        )
+    }
+}
+
+impl error::Error for InvalidParametersError {}
 
+/// A cycle-aware quality score generator.
+///
+/// Real sequencers do not sample every base from the same distribution: quality degrades towards
+/// the end of a read as the chemistry runs down. This models that degradation as a decay curve
+/// from `q_start` towards `q_end`, shaped by `shape`, with the standard deviation optionally
+/// widening towards the end of the read (`std_dev_start` to `std_dev_end`).
 pub struct QualityScores {
-    distribution: Normal<f64>,
+    q_start: f64,
+    q_end: f64,
+    shape: f64,
+    std_dev_start: f64,
+    std_dev_end: f64,
+}
+
+impl QualityScores {
+    /// Creates a generator from the parameters of its decay curve.
+    ///
+    /// `q_start` and `q_end` are expected to be in the range `[0.0, 41.0]`, `shape` is expected
+    /// to be positive, and `std_dev_start` and `std_dev_end` are expected to be non-negative. An
+    /// error is returned otherwise, since an out-of-range `std_dev` would otherwise only surface
+    /// as a panic when a score is sampled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fqlib::distributions::QualityScores;
+    ///
+    /// assert!(QualityScores::new(38.0, 10.0, 3.0, 2.0, 4.61).is_ok());
+    /// assert!(QualityScores::new(-1.0, 10.0, 3.0, 2.0, 4.61).is_err());
+    /// ```
+    pub fn new(
+        q_start: f64,
+        q_end: f64,
+        shape: f64,
+        std_dev_start: f64,
+        std_dev_end: f64,
+    ) -> Result<QualityScores, InvalidParametersError> {
+        let in_range = |x: f64| (MIN..=MAX).contains(&x);
+
+        if !in_range(q_start)
+            || !in_range(q_end)
+            || shape <= 0.0
+            || std_dev_start < 0.0
+            || std_dev_end < 0.0
+        {
+            return Err(InvalidParametersError {
+                q_start,
+                q_end,
+                shape,
+                std_dev_start,
+                std_dev_end,
+            });
+        }
+
+        Ok(QualityScores {
+            q_start,
+            q_end,
+            shape,
+            std_dev_start,
+            std_dev_end,
+        })
+    }
+
+    /// Samples a quality score for a given cycle (0-based position) in a read of the given
+    /// length.
+    pub fn sample_at<R>(&self, rng: &mut R, cycle: usize, read_length: usize) -> u8
+    where
+        R: Rng + ?Sized,
+    {
+        let t = if read_length > 1 {
+            cycle as f64 / (read_length - 1) as f64
+        } else {
+            0.0
+        };
+
+        let mean = self.q_start - (self.q_start - self.q_end) * t.powf(self.shape);
+        let std_dev = self.std_dev_start + (self.std_dev_end - self.std_dev_start) * t;
+
+        let distribution = Normal::new(mean, std_dev).unwrap();
+        let n = distribution.sample(rng);
+
+        clamp(n, MIN, MAX).round() as u8
+    }
 }
 
 impl Default for QualityScores {
+    /// Creates a generator modeling a typical Illumina run.
     fn default() -> Self {
-        Self {
-            // Std. dev. is never < 0.0.
-            distribution: Normal::new(MEAN, STD_DEV).unwrap(),
-        }
+        Self::new(Q_START, Q_END, SHAPE, STD_DEV_START, STD_DEV_END).unwrap()
     }
 }
 
 impl Distribution<u8> for QualityScores {
+    /// Samples a quality score as if from the first cycle of a read.
+    ///
+    /// For position-aware sampling across a whole read, use [`sample_at`].
+    ///
+    /// [`sample_at`]: #method.sample_at
     fn sample<R>(&self, rng: &mut R) -> u8
     where
         R: Rng + ?Sized,
     {
-        let n = self.distribution.sample(rng);
-        let score = clamp(n, MIN, MAX).round();
-        score as u8
+        self.sample_at(rng, 0, 1)
     }
 }
 
@@ -46,6 +160,15 @@ fn clamp(n: f64, min: f64, max: f64) -> f64 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_new_with_invalid_parameters() {
+        assert!(QualityScores::new(-1.0, Q_END, SHAPE, STD_DEV_START, STD_DEV_END).is_err());
+        assert!(QualityScores::new(Q_START, 42.0, SHAPE, STD_DEV_START, STD_DEV_END).is_err());
+        assert!(QualityScores::new(Q_START, Q_END, 0.0, STD_DEV_START, STD_DEV_END).is_err());
+        assert!(QualityScores::new(Q_START, Q_END, SHAPE, -1.0, STD_DEV_END).is_err());
+        assert!(QualityScores::new(Q_START, Q_END, SHAPE, STD_DEV_START, -1.0).is_err());
+    }
+
     #[test]
     fn test_clamp() {
         assert_eq!(clamp(0.0, 0.0, 1.0), 0.0);
@@ -54,4 +177,15 @@ mod tests {
         assert_eq!(clamp(-1.0, 0.0, 1.0), 0.0);
         assert_eq!(clamp(2.0, 0.0, 1.0), 1.0);
     }
+
+    #[test]
+    fn test_sample_at_bounds() {
+        let quality_scores = QualityScores::default();
+        let mut rng = rand::thread_rng();
+
+        for cycle in 0..100 {
+            let score = quality_scores.sample_at(&mut rng, cycle, 100);
+            assert!(score as f64 >= MIN && score as f64 <= MAX);
+        }
+    }
 }
@@ -0,0 +1,323 @@
+use std::{error, fmt};
+
+use serde::Deserialize;
+
+use validators::paired::{PairedNameValidator, PairedReadValidator};
+use validators::single::{AlphabetValidator, DuplicateNameValidator, RegexNameValidator};
+use validators::{SingleReadValidator, SingleReadValidatorMut, ValidationLevel};
+
+/// A validation suite read from a TOML configuration file.
+///
+/// # Examples
+///
+/// ```toml
+/// min_level = "medium"
+///
+/// [[validators]]
+/// code = "S002"
+/// options = { characters = "ACGTN" }
+///
+/// [[validators]]
+/// code = "S007"
+///
+/// [[validators]]
+/// code = "S008"
+/// level = "low"
+/// options = { pattern = "^@fqlib:\\d+$" }
+///
+/// [[validators]]
+/// code = "P001"
+/// options = { suffixes = ["_R1", "_R2"] }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// The minimum level a validator must be at to be included in the built suite.
+    pub min_level: Option<String>,
+
+    /// The list of validators to enable, in the order they should run.
+    #[serde(default)]
+    pub validators: Vec<ValidatorConfig>,
+}
+
+/// A single entry in the validation suite.
+#[derive(Debug, Deserialize)]
+pub struct ValidatorConfig {
+    /// The validator's code, e.g. `"S002"`.
+    pub code: String,
+
+    /// An override for the validator's level, used when filtering against `min_level`.
+    pub level: Option<String>,
+
+    /// Free-form, validator-specific options, e.g. the alphabet for `AlphabetValidator` or the
+    /// pattern for `RegexNameValidator`.
+    #[serde(default)]
+    pub options: toml::value::Table,
+}
+
+/// An error building a validation suite from a [`Config`].
+///
+/// [`Config`]: struct.Config.html
+#[derive(Debug)]
+pub enum Error {
+    Toml(toml::de::Error),
+    UnknownValidator(String),
+    UnknownLevel(String),
+    InvalidOption(&'static str),
+    InvalidPattern(regex::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Toml(e) => write!(f, "invalid TOML: {}", e),
+            Error::UnknownValidator(code) => write!(f, "unknown validator code: {}", code),
+            Error::UnknownLevel(level) => write!(f, "unknown validation level: {}", level),
+            Error::InvalidOption(name) => write!(f, "invalid option: {}", name),
+            Error::InvalidPattern(e) => write!(f, "invalid pattern: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<toml::de::Error> for Error {
+    fn from(error: toml::de::Error) -> Self {
+        Error::Toml(error)
+    }
+}
+
+/// Parses a validation suite from a TOML document.
+pub fn parse(src: &str) -> Result<Config, Error> {
+    toml::from_str(src).map_err(Error::from)
+}
+
+/// Builds the validators (single immutable, single stateful, and paired) described by a
+/// [`Config`], dropping any entry below `config.min_level`.
+///
+/// [`Config`]: struct.Config.html
+#[allow(clippy::type_complexity)]
+pub fn build_validators(
+    config: &Config,
+) -> Result<
+    (
+        Vec<Box<dyn SingleReadValidator>>,
+        Vec<Box<dyn SingleReadValidatorMut>>,
+        Vec<Box<dyn PairedReadValidator>>,
+    ),
+    Error,
+> {
+    let min_level = match &config.min_level {
+        Some(level) => parse_level(level)?,
+        None => ValidationLevel::Low,
+    };
+
+    let mut validators: Vec<Box<dyn SingleReadValidator>> = Vec::new();
+    let mut validators_mut: Vec<Box<dyn SingleReadValidatorMut>> = Vec::new();
+    let mut paired_validators: Vec<Box<dyn PairedReadValidator>> = Vec::new();
+
+    for entry in &config.validators {
+        let level = match &entry.level {
+            Some(level) => parse_level(level)?,
+            None => default_level(&entry.code)?,
+        };
+
+        if level < min_level {
+            continue;
+        }
+
+        match entry.code.as_str() {
+            "S002" => validators.push(Box::new(build_alphabet_validator(entry)?)),
+            "S008" => validators.push(Box::new(build_regex_name_validator(entry)?)),
+            "S007" => validators_mut.push(Box::new(DuplicateNameValidator::new())),
+            "P001" => paired_validators.push(Box::new(build_paired_name_validator(entry)?)),
+            code => return Err(Error::UnknownValidator(code.to_string())),
+        }
+    }
+
+    Ok((validators, validators_mut, paired_validators))
+}
+
+fn build_alphabet_validator(entry: &ValidatorConfig) -> Result<AlphabetValidator, Error> {
+    match entry.options.get("characters") {
+        Some(value) => {
+            let characters = value.as_str().ok_or(Error::InvalidOption("characters"))?;
+            Ok(AlphabetValidator::new(characters.as_bytes()))
+        }
+        None => Ok(AlphabetValidator::default()),
+    }
+}
+
+fn build_regex_name_validator(entry: &ValidatorConfig) -> Result<RegexNameValidator, Error> {
+    match entry.options.get("pattern") {
+        Some(value) => {
+            let pattern = value.as_str().ok_or(Error::InvalidOption("pattern"))?;
+            RegexNameValidator::new(pattern).map_err(Error::InvalidPattern)
+        }
+        None => Ok(RegexNameValidator::default()),
+    }
+}
+
+fn build_paired_name_validator(entry: &ValidatorConfig) -> Result<PairedNameValidator, Error> {
+    match entry.options.get("suffixes") {
+        Some(value) => {
+            let values = value.as_array().ok_or(Error::InvalidOption("suffixes"))?;
+            let suffixes = values
+                .iter()
+                .map(|v| v.as_str().map(|s| s.as_bytes().to_vec()))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(Error::InvalidOption("suffixes"))?;
+            Ok(PairedNameValidator::new(suffixes))
+        }
+        None => Ok(PairedNameValidator::default()),
+    }
+}
+
+fn default_level(code: &str) -> Result<ValidationLevel, Error> {
+    match code {
+        "S002" => Ok(ValidationLevel::Medium),
+        "S007" => Ok(ValidationLevel::High),
+        "S008" => Ok(ValidationLevel::Medium),
+        "P001" => Ok(ValidationLevel::High),
+        code => Err(Error::UnknownValidator(code.to_string())),
+    }
+}
+
+fn parse_level(level: &str) -> Result<ValidationLevel, Error> {
+    match level.to_lowercase().as_str() {
+        "low" => Ok(ValidationLevel::Low),
+        "medium" => Ok(ValidationLevel::Medium),
+        "high" => Ok(ValidationLevel::High),
+        _ => Err(Error::UnknownLevel(level.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Block;
+
+    #[test]
+    fn test_parse() {
+        let src = r#"
+            min_level = "medium"
+
+            [[validators]]
+            code = "S002"
+            options = { characters = "ACGT" }
+
+            [[validators]]
+            code = "S007"
+        "#;
+
+        let config = parse(src).unwrap();
+        assert_eq!(config.min_level, Some(String::from("medium")));
+        assert_eq!(config.validators.len(), 2);
+    }
+
+    #[test]
+    fn test_build_validators_filters_by_min_level() {
+        let src = r#"
+            min_level = "high"
+
+            [[validators]]
+            code = "S002"
+
+            [[validators]]
+            code = "S007"
+        "#;
+
+        let config = parse(src).unwrap();
+        let (validators, validators_mut, paired_validators) = build_validators(&config).unwrap();
+
+        assert!(validators.is_empty());
+        assert_eq!(validators_mut.len(), 1);
+        assert!(paired_validators.is_empty());
+    }
+
+    #[test]
+    fn test_build_validators_with_paired_validator() {
+        let src = r#"
+            [[validators]]
+            code = "P001"
+        "#;
+
+        let config = parse(src).unwrap();
+        let (validators, validators_mut, paired_validators) = build_validators(&config).unwrap();
+
+        assert!(validators.is_empty());
+        assert!(validators_mut.is_empty());
+        assert_eq!(paired_validators.len(), 1);
+    }
+
+    #[test]
+    fn test_build_validators_with_paired_validator_options() {
+        let src = r#"
+            [[validators]]
+            code = "P001"
+            options = { suffixes = ["_R1", "_R2"] }
+        "#;
+
+        let config = parse(src).unwrap();
+        let (_, _, paired_validators) = build_validators(&config).unwrap();
+
+        let a = Block::new("@fqlib:1_R1", "", "", "");
+        let b = Block::new("@fqlib:1_R2", "", "", "");
+        assert!(paired_validators[0].validate(&a, &b).is_ok());
+    }
+
+    #[test]
+    fn test_build_validators_with_paired_validator_wrong_option_type() {
+        let src = r#"
+            [[validators]]
+            code = "P001"
+            options = { suffixes = "_R1" }
+        "#;
+
+        let config = parse(src).unwrap();
+        assert!(matches!(
+            build_validators(&config),
+            Err(Error::InvalidOption("suffixes"))
+        ));
+    }
+
+    #[test]
+    fn test_build_validators_with_unknown_code() {
+        let src = r#"
+            [[validators]]
+            code = "S999"
+        "#;
+
+        let config = parse(src).unwrap();
+        assert!(build_validators(&config).is_err());
+    }
+
+    #[test]
+    fn test_build_validators_with_wrong_option_type() {
+        let src = r#"
+            [[validators]]
+            code = "S002"
+            options = { characters = 5 }
+        "#;
+
+        let config = parse(src).unwrap();
+        assert!(matches!(
+            build_validators(&config),
+            Err(Error::InvalidOption("characters"))
+        ));
+    }
+
+    #[test]
+    fn test_build_validators_with_invalid_pattern() {
+        let src = r#"
+            [[validators]]
+            code = "S008"
+            options = { pattern = "(" }
+        "#;
+
+        let config = parse(src).unwrap();
+        assert!(matches!(
+            build_validators(&config),
+            Err(Error::InvalidPattern(_))
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+//! Validators that operate on a pair of blocks, one from each mate file of paired-end data.
+
+mod name;
+
+pub use self::name::PairedNameValidator;
+
+use Block;
+use validators::{Error, ValidationLevel};
+
+/// A trait for a single-pass validator that compares a block from an R1 file against its mate
+/// in an R2 file.
+pub trait PairedReadValidator {
+    /// The code that uniquely identifies this validator, e.g. "P001".
+    fn code(&self) -> &'static str;
+
+    /// The name of this validator.
+    fn name(&self) -> &'static str;
+
+    /// The level of this validator.
+    fn level(&self) -> ValidationLevel;
+
+    /// Validates a pair of blocks, one from each mate.
+    fn validate(&self, a: &Block, b: &Block) -> Result<(), Error>;
+}
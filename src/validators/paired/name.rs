@@ -0,0 +1,155 @@
+use Block;
+use validators::{Error, LineType, PairedReadValidator, ValidationLevel};
+
+/// [P001] (high) Validator to check that paired block names correspond to each other.
+///
+/// Read names commonly carry a mate suffix, either the legacy `/1`/`/2` form or the Casava `
+/// 1:`/` 2:` field. This validator strips a configurable set of such suffixes from each name and
+/// fails when the remaining cores differ, catching desynchronized or mismatched paired-end files
+/// before alignment.
+///
+/// # Examples
+///
+/// ```
+/// use fqlib::Block;
+/// use fqlib::validators::paired::{PairedNameValidator, PairedReadValidator};
+///
+/// let validator = PairedNameValidator::default();
+///
+/// let a = Block::new("@fqlib:1/1", "", "", "");
+/// let b = Block::new("@fqlib:1/2", "", "", "");
+/// assert!(validator.validate(&a, &b).is_ok());
+///
+/// let c = Block::new("@fqlib:2/2", "", "", "");
+/// assert!(validator.validate(&a, &c).is_err());
+/// ```
+pub struct PairedNameValidator {
+    suffixes: Vec<Vec<u8>>,
+}
+
+impl PairedNameValidator {
+    /// Creates a validator that trims the given list of mate suffixes (e.g. `b"/1"`) before
+    /// comparing names.
+    pub fn new(suffixes: Vec<Vec<u8>>) -> PairedNameValidator {
+        PairedNameValidator { suffixes }
+    }
+
+    /// Returns the name with any configured mate suffix removed.
+    ///
+    /// This also recognizes the Casava ` 1:.../` 2:...` field and trims everything from the
+    /// first space onward when present.
+    fn trim<'a>(&self, name: &'a [u8]) -> &'a [u8] {
+        for suffix in &self.suffixes {
+            if name.ends_with(suffix.as_slice()) {
+                return &name[..name.len() - suffix.len()];
+            }
+        }
+
+        if let Some(i) = name.iter().position(|&b| b == b' ') {
+            let (head, tail) = name.split_at(i);
+
+            if let [_, b'1', b':', ..] | [_, b'2', b':', ..] = tail {
+                return head;
+            }
+        }
+
+        name
+    }
+}
+
+impl Default for PairedNameValidator {
+    /// Creates a validator that trims the conventional `/1` and `/2` mate suffixes.
+    fn default() -> PairedNameValidator {
+        PairedNameValidator::new(vec![b"/1".to_vec(), b"/2".to_vec()])
+    }
+}
+
+impl PairedReadValidator for PairedNameValidator {
+    fn code(&self) -> &'static str {
+        "P001"
+    }
+
+    fn name(&self) -> &'static str {
+        "PairedNameValidator"
+    }
+
+    fn level(&self) -> ValidationLevel {
+        ValidationLevel::High
+    }
+
+    fn validate(&self, a: &Block, b: &Block) -> Result<(), Error> {
+        let x = self.trim(a.name());
+        let y = self.trim(b.name());
+
+        if x == y {
+            Ok(())
+        } else {
+            Err(Error::new(
+                self.code(),
+                self.name(),
+                &format!(
+                    "Mismatched read names: '{}', '{}'",
+                    String::from_utf8_lossy(a.name()),
+                    String::from_utf8_lossy(b.name()),
+                ),
+                LineType::Name,
+                Some(1),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_with_slash_suffix() {
+        let validator = PairedNameValidator::default();
+        assert_eq!(validator.trim(b"@fqlib:1/1"), b"@fqlib:1");
+        assert_eq!(validator.trim(b"@fqlib:1/2"), b"@fqlib:1");
+    }
+
+    #[test]
+    fn test_trim_with_casava_field() {
+        let validator = PairedNameValidator::default();
+        assert_eq!(
+            validator.trim(b"@fqlib:1 1:N:0:ATCACG"),
+            b"@fqlib:1"
+        );
+        assert_eq!(
+            validator.trim(b"@fqlib:1 2:N:0:ATCACG"),
+            b"@fqlib:1"
+        );
+    }
+
+    #[test]
+    fn test_code() {
+        let validator = PairedNameValidator::default();
+        assert_eq!(validator.code(), "P001");
+    }
+
+    #[test]
+    fn test_name() {
+        let validator = PairedNameValidator::default();
+        assert_eq!(validator.name(), "PairedNameValidator");
+    }
+
+    #[test]
+    fn test_level() {
+        let validator = PairedNameValidator::default();
+        assert_eq!(validator.level(), ValidationLevel::High);
+    }
+
+    #[test]
+    fn test_validate() {
+        let validator = PairedNameValidator::default();
+
+        let a = Block::new("@fqlib:1/1", "", "", "");
+        let b = Block::new("@fqlib:1/2", "", "", "");
+        assert!(validator.validate(&a, &b).is_ok());
+
+        let c = Block::new("@fqlib:2/2", "", "", "");
+        assert!(validator.validate(&a, &c).is_err());
+    }
+}
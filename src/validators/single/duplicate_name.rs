@@ -8,6 +8,20 @@ use validators::{Error, LineType, SingleReadValidatorMut, ValidationLevel};
 const FALSE_POSITIVE_PROBABILITY: f64 = 0.0001;
 const INITIAL_CAPACITY: usize = 10000;
 
+/// The strategy used to confirm a possible duplicate flagged by the first-pass Bloom filter.
+enum Duplicates {
+    /// Names are kept verbatim in a `HashMap`, so a duplicate is only ever reported when the
+    /// exact byte sequence truly recurs. Memory use scales with the number of possible
+    /// duplicates (i.e. names that hit the first-pass filter), since each is stored in full.
+    Exact(HashMap<Vec<u8>, u8>),
+
+    /// Names are not stored; a second, scalable Bloom filter of every name seen in the validation
+    /// pass is consulted instead. Its memory use grows with the number of names validated rather
+    /// than with the number of possible duplicates, at the cost of inheriting the filter's
+    /// false-positive probability: an unrelated name may occasionally be reported as a duplicate.
+    Approximate(ScalableBloomFilter),
+}
+
 /// [S007] (high) Validator to check if all block names are unique.
 ///
 /// The implementation of this validator uses a Bloom filter, a probabilistic data structure.
@@ -15,6 +29,11 @@ const INITIAL_CAPACITY: usize = 10000;
 /// ([`insert`]), which may or may not hit duplicates; and the second, checking that list of
 /// possible duplicates ([`validate`]).
 ///
+/// By default ([`new`]), possible duplicates are confirmed exactly, so a report always reflects
+/// a true duplicate. Use [`with_exact_matching`] to opt into an approximate mode that discards
+/// the exact names in favor of a second Bloom filter, trading the filter's false-positive rate
+/// for a memory footprint that no longer grows with the number of possible duplicates.
+///
 /// # Examples
 ///
 /// ```
@@ -41,19 +60,48 @@ const INITIAL_CAPACITY: usize = 10000;
 ///
 /// [`insert`]: #method.insert
 /// [`validate`]: #method.validate
+/// [`new`]: #method.new
+/// [`with_exact_matching`]: #method.with_exact_matching
 pub struct DuplicateNameValidator {
     filter: ScalableBloomFilter,
-    possible_duplicates: HashMap<Vec<u8>, u8>,
+    duplicates: Duplicates,
+    has_possible_duplicates: bool,
 }
 
 impl DuplicateNameValidator {
+    /// Creates a validator that confirms possible duplicates exactly.
     pub fn new() -> DuplicateNameValidator {
-        DuplicateNameValidator {
-            filter: ScalableBloomFilter::new(
+        DuplicateNameValidator::with_exact_matching(true)
+    }
+
+    /// Creates a validator, toggling whether possible duplicates are confirmed exactly.
+    ///
+    /// Exact matching (`true`) keeps every possible duplicate's name in memory so a report is
+    /// always a true duplicate. Approximate matching (`false`) instead adds each validated name
+    /// to a second, scalable Bloom filter to confirm duplicates, so no name is stored in full,
+    /// but inherits that filter's false-positive probability.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fqlib::validators::single::DuplicateNameValidator;
+    ///
+    /// let validator = DuplicateNameValidator::with_exact_matching(false);
+    /// ```
+    pub fn with_exact_matching(exact: bool) -> DuplicateNameValidator {
+        let duplicates = if exact {
+            Duplicates::Exact(HashMap::new())
+        } else {
+            Duplicates::Approximate(ScalableBloomFilter::new(
                 FALSE_POSITIVE_PROBABILITY,
                 INITIAL_CAPACITY,
-            ),
-            possible_duplicates: HashMap::new(),
+            ))
+        };
+
+        DuplicateNameValidator {
+            filter: ScalableBloomFilter::new(FALSE_POSITIVE_PROBABILITY, INITIAL_CAPACITY),
+            duplicates,
+            has_possible_duplicates: false,
         }
     }
 }
@@ -61,7 +109,9 @@ impl DuplicateNameValidator {
 impl DuplicateNameValidator {
     /// Adds a block name to the set.
     ///
-    /// This also records possible duplicates to be used in the validation pass.
+    /// This also records possible duplicates to be used in the validation pass. Under exact
+    /// matching, a name is only ever recorded once per distinct byte sequence; inserting it
+    /// again is a no-op, since the insert and validate passes never interleave.
     ///
     /// # Examples
     ///
@@ -77,7 +127,11 @@ impl DuplicateNameValidator {
         let name = b.name();
 
         if self.filter.contains_or_insert(name) {
-            self.possible_duplicates.insert(name.to_vec(), 0);
+            self.has_possible_duplicates = true;
+
+            if let Duplicates::Exact(possible_duplicates) = &mut self.duplicates {
+                possible_duplicates.entry(name.to_vec()).or_insert(0);
+            }
         }
     }
 
@@ -96,7 +150,7 @@ impl DuplicateNameValidator {
     /// assert!(validator.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.possible_duplicates.is_empty()
+        !self.has_possible_duplicates
     }
 }
 
@@ -117,18 +171,30 @@ impl SingleReadValidatorMut for DuplicateNameValidator {
         let code = self.code();
         let name = self.name();
 
-        if let Some(count) = self.possible_duplicates.get_mut(&b.name) {
-            if *count >= 1 {
-                return Err(Error::new(
-                    code,
-                    name,
-                    &format!("Duplicate found: '{}'", String::from_utf8_lossy(b.name())),
-                    LineType::Name,
-                    Some(1),
-                ));
-            }
+        if !self.has_possible_duplicates {
+            return Ok(());
+        }
+
+        let is_duplicate = match &mut self.duplicates {
+            Duplicates::Exact(possible_duplicates) => match possible_duplicates.get_mut(b.name()) {
+                Some(count) if *count >= 1 => true,
+                Some(count) => {
+                    *count += 1;
+                    false
+                }
+                None => false,
+            },
+            Duplicates::Approximate(seen) => seen.contains_or_insert(b.name()),
+        };
 
-            *count += 1;
+        if is_duplicate {
+            return Err(Error::new(
+                code,
+                name,
+                &format!("Duplicate found: '{}'", String::from_utf8_lossy(b.name())),
+                LineType::Name,
+                Some(1),
+            ));
         }
 
         Ok(())
@@ -138,10 +204,19 @@ impl SingleReadValidatorMut for DuplicateNameValidator {
 #[cfg(test)]
 mod tests {
     use super::DuplicateNameValidator;
+
+    use Block;
     use validators::{SingleReadValidatorMut, ValidationLevel};
 
     #[test]
     fn test_is_empty() {
+        let mut validator = DuplicateNameValidator::new();
+        assert!(validator.is_empty());
+
+        let b = Block::new("@fqlib:1", "", "", "");
+        validator.insert(&b);
+        validator.insert(&b);
+        assert!(!validator.is_empty());
     }
 
     #[test]
@@ -161,4 +236,36 @@ mod tests {
         let validator = DuplicateNameValidator::new();
         assert_eq!(validator.level(), ValidationLevel::High);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_validate() {
+        let mut validator = DuplicateNameValidator::new();
+
+        let b = Block::new("@fqlib:1", "", "", "");
+        let d = Block::new("@fqlib:2", "", "", "");
+
+        validator.insert(&b);
+        validator.insert(&d);
+        validator.insert(&d);
+
+        assert!(validator.validate(&b).is_ok());
+        assert!(validator.validate(&d).is_ok());
+        assert!(validator.validate(&d).is_err());
+    }
+
+    #[test]
+    fn test_validate_with_approximate_matching() {
+        let mut validator = DuplicateNameValidator::with_exact_matching(false);
+
+        let b = Block::new("@fqlib:1", "", "", "");
+        let d = Block::new("@fqlib:2", "", "", "");
+
+        validator.insert(&b);
+        validator.insert(&d);
+        validator.insert(&d);
+
+        assert!(validator.validate(&b).is_ok());
+        assert!(validator.validate(&d).is_ok());
+        assert!(validator.validate(&d).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use regex::bytes::Regex;
+
+use Block;
+use validators::{Error, LineType, SingleReadValidator, ValidationLevel};
+
+/// The read name grammar used by Illumina 1.8+ instruments.
+///
+/// ```text
+/// @<instrument>:<run number>:<flowcell ID>:<lane>:<tile>:<x-pos>:<y-pos> <read>:<is filtered>:<control number>:<index>
+/// ```
+const ILLUMINA_1_8_PATTERN: &str =
+    r"^[!-~]+:\d+:[!-~]+:\d+:\d+:\d+:\d+(?: [12]:[YN]:\d+:[!-~]*)?$";
+
+/// [S008] (medium) Validator to check that block names conform to a given naming convention.
+///
+/// Names are checked against a user-supplied regular expression, letting labs assert that a
+/// run's read names follow a structured convention, e.g. an Illumina instrument header. Because
+/// names are raw bytes rather than necessarily valid UTF-8, matching is done with
+/// [`regex::bytes::Regex`].
+///
+/// [`regex::bytes::Regex`]: https://docs.rs/regex/*/regex/bytes/struct.Regex.html
+pub struct RegexNameValidator {
+    re: Regex,
+}
+
+impl RegexNameValidator {
+    /// Creates a validator from a regular expression pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fqlib::validators::single::RegexNameValidator;
+    ///
+    /// assert!(RegexNameValidator::new(r"^@fqlib:\d+$").is_ok());
+    /// assert!(RegexNameValidator::new(r"(").is_err());
+    /// ```
+    pub fn new(pattern: &str) -> Result<RegexNameValidator, regex::Error> {
+        Regex::new(pattern).map(|re| RegexNameValidator { re })
+    }
+}
+
+impl SingleReadValidator for RegexNameValidator {
+    fn code(&self) -> &'static str {
+        "S008"
+    }
+
+    fn name(&self) -> &'static str {
+        "RegexNameValidator"
+    }
+
+    fn level(&self) -> ValidationLevel {
+        ValidationLevel::Medium
+    }
+
+    fn validate(&self, b: &Block) -> Result<(), Error> {
+        if self.re.is_match(b.name()) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                self.code(),
+                self.name(),
+                &format!(
+                    "Name does not match pattern '{}': '{}'",
+                    self.re.as_str(),
+                    String::from_utf8_lossy(b.name()),
+                ),
+                LineType::Name,
+                Some(1),
+            ))
+        }
+    }
+}
+
+impl Default for RegexNameValidator {
+    /// Creates a validator that accepts Illumina 1.8+ style read names.
+    fn default() -> RegexNameValidator {
+        RegexNameValidator::new(ILLUMINA_1_8_PATTERN).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegexNameValidator;
+
+    use Block;
+    use validators::{SingleReadValidator, ValidationLevel};
+
+    #[test]
+    fn test_new() {
+        assert!(RegexNameValidator::new(r"^@fqlib:\d+$").is_ok());
+        assert!(RegexNameValidator::new(r"(").is_err());
+    }
+
+    #[test]
+    fn test_code() {
+        let validator = RegexNameValidator::default();
+        assert_eq!(validator.code(), "S008");
+    }
+
+    #[test]
+    fn test_name() {
+        let validator = RegexNameValidator::default();
+        assert_eq!(validator.name(), "RegexNameValidator");
+    }
+
+    #[test]
+    fn test_level() {
+        let validator = RegexNameValidator::default();
+        assert_eq!(validator.level(), ValidationLevel::Medium);
+    }
+
+    #[test]
+    fn test_validate() {
+        let validator = RegexNameValidator::default();
+
+        let block = Block::new(
+            "@fqlib:1101:H2V2YADXX:1:1101:1000:2000 1:N:0:ATCACG",
+            "",
+            "",
+            "",
+        );
+        assert!(validator.validate(&block).is_ok());
+
+        let block = Block::new("@not-a-valid-name", "", "", "");
+        assert!(validator.validate(&block).is_err());
+    }
+}